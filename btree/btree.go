@@ -204,6 +204,27 @@ func (btree *Btree[TK, TV]) FindOneWithID(ctx context.Context, key TK, id sop.UU
 	}
 }
 
+// FindOneOrPrevious will find the item with such key or, if not found, the item with the greatest key less than the key.
+func (btree *Btree[TK, TV]) FindOneOrPrevious(ctx context.Context, key TK) (bool, error) {
+	// return false & no error if B-Tree is empty.
+	if btree.StoreInfo.Count == 0 {
+		return false, nil
+	}
+	if ok, err := btree.FindOne(ctx, key, false); ok || err != nil {
+		return ok, err
+	}
+	// FindOne positions the current item to the one nearest the key if not found,
+	// it can be the item with the next greater key or the previous lesser key.
+	item, err := btree.getCurrentItem(ctx)
+	if err != nil || item == nil {
+		return false, err
+	}
+	if Compare[TK](item.Key, key) < 0 {
+		return true, nil
+	}
+	return btree.Previous(ctx)
+}
+
 // GetCurrentKey returns the current item's key part.
 func (btree *Btree[TK, TV]) GetCurrentKey() TK {
 	var zero TK
@@ -502,6 +523,11 @@ func (btree *Btree[TK, TV]) IsUnique() bool {
 	return btree.StoreInfo.IsUnique
 }
 
+// GetStoreInfo returns a copy of the B-Tree's store info.
+func (btree *Btree[TK, TV]) GetStoreInfo() StoreInfo {
+	return *btree.StoreInfo
+}
+
 // saveNode will prepare & persist (if needed) the Node to the backend
 // via NodeRepository call. When Transaction Manager is implemented, this
 // will just register the modified/new node in the transaction session
@@ -35,6 +35,10 @@ type BtreeInterface[TK Comparable, TV any] interface {
 	// This is useful for B-Tree that allows duplicate keys(IsUnique = false) as it provides a way to
 	// differentiate duplicated keys via the unique ID(sop.UUID).
 	FindOneWithID(ctx context.Context, key TK, id sop.UUID) (bool, error)
+	// FindOneOrPrevious will search Btree for an item with a given key or, if not found, the item with the
+	// greatest key less than the given key, i.e. - "floor" lookup. Return true if such item is found, otherwise false.
+	// Combined with Previous, allows descending range scan starting at an arbitrary key.
+	FindOneOrPrevious(ctx context.Context, key TK) (bool, error)
 	// GetCurrentKey returns the current item's key.
 	GetCurrentKey() TK
 	// GetCurrentValue returns the current item's value.
@@ -66,6 +70,10 @@ type BtreeInterface[TK Comparable, TV any] interface {
 
 	// Returns the number of items in this B-Tree.
 	Count() int64
+
+	// GetStoreInfo returns the B-Tree's store info, e.g. to check the slot length, value data
+	// storage & load balancing settings it was created with, as persisted in the backend.
+	GetStoreInfo() StoreInfo
 }
 
 // NodeRepository interface specifies the node repository.
@@ -55,6 +55,13 @@ func (b3 BtreeInterface[TK, TV]) FindOne(key TK, firstItemWithKey bool) bool {
 	return ok
 }
 
+// FindOneOrPrevious will search Btree for an item with a given key or, if not found, the item with the
+// greatest key less than the given key. Return true if such item is found, otherwise false.
+func (b3 BtreeInterface[TK, TV]) FindOneOrPrevious(key TK) bool {
+	ok, _ := b3.btree.FindOneOrPrevious(nil, key)
+	return ok
+}
+
 // GetCurrentKey returns the current item's key.
 func (b3 BtreeInterface[TK, TV]) GetCurrentKey() TK {
 	return b3.btree.GetCurrentKey()
@@ -121,3 +128,8 @@ func (b3 BtreeInterface[TK, TV]) Previous() bool {
 func (b3 BtreeInterface[TK, TV]) IsUnique() bool {
 	return b3.btree.IsUnique()
 }
+
+// GetStoreInfo returns the B-Tree's store info.
+func (b3 BtreeInterface[TK, TV]) GetStoreInfo() btree.StoreInfo {
+	return b3.btree.GetStoreInfo()
+}
@@ -392,3 +392,49 @@ func Test_SimpleDataMgmtCases(t *testing.T) {
 	}
 	fmt.Printf("Btree simple data mgmt tests ended.\n\n")
 }
+
+func Test_FindOneOrPrevious(t *testing.T) {
+	b3 := NewBtree[int, string](true)
+
+	if b3.FindOneOrPrevious(10) {
+		t.Errorf("FindOneOrPrevious(10) on empty tree failed, got true, want false.")
+	}
+	// Populate with even numbered keys so odd numbered keys are missing, spanning multiple nodes.
+	for i := 2; i <= 200; i += 2 {
+		b3.Add(i, fmt.Sprintf("value %d", i))
+	}
+	for i := 2; i <= 201; i++ {
+		want := i - i%2
+		if !b3.FindOneOrPrevious(i) || b3.GetCurrentKey() != want {
+			t.Errorf("FindOneOrPrevious(%d) failed, got = %v, want = %d.", i, b3.GetCurrentKey(), want)
+		}
+	}
+	if b3.FindOneOrPrevious(1) {
+		t.Errorf("FindOneOrPrevious(1) failed, got = %v, want no item found.", b3.GetCurrentKey())
+	}
+	// Descending scan starting from a missing key.
+	if !b3.FindOneOrPrevious(7) || b3.GetCurrentKey() != 6 {
+		t.Errorf("FindOneOrPrevious(7) failed, got = %v, want = 6.", b3.GetCurrentKey())
+	}
+	if !b3.Previous() || b3.GetCurrentKey() != 4 {
+		t.Errorf("Previous() failed, got = %v, want = 4.", b3.GetCurrentKey())
+	}
+}
+
+func Test_GetStoreInfo(t *testing.T) {
+	b3 := NewBtree[int, string](true)
+
+	si := b3.GetStoreInfo()
+	if si.SlotLength != 8 || !si.IsUnique {
+		t.Errorf("GetStoreInfo() failed, got SlotLength, IsUnique = %v, %v, want = 8, true.", si.SlotLength, si.IsUnique)
+	}
+	if si.Count != 0 {
+		t.Errorf("GetStoreInfo() failed, got Count = %v, want = 0.", si.Count)
+	}
+	for i := 1; i <= 20; i++ {
+		b3.Add(i, fmt.Sprintf("value %d", i))
+	}
+	if si := b3.GetStoreInfo(); si.Count != 20 {
+		t.Errorf("GetStoreInfo() failed, got Count = %v, want = 20.", si.Count)
+	}
+}
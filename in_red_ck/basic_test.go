@@ -1,6 +1,7 @@
 package in_red_ck
 
 import (
+	"slices"
 	"testing"
 
 	"github.com/SharedCode/sop"
@@ -147,3 +148,58 @@ func Test_UniqueKeyBTreeOnMultipleCommits(t *testing.T) {
 		t.Log(err)
 	}
 }
+
+func Test_GetStoreNames(t *testing.T) {
+	trans, _ := newMockTransaction(t, ForWriting, -1)
+	trans.Begin()
+	NewBtree[int, string](ctx, sop.StoreOptions{
+		Name:                     "storeNamesFoo",
+		SlotLength:               8,
+		IsUnique:                 false,
+		IsValueDataInNodeSegment: true,
+		LeafLoadBalancing:        true,
+		Description:              "",
+	}, trans)
+	if err := trans.Commit(ctx); err != nil {
+		t.Errorf("Commit returned error, details: %v.", err)
+	}
+	names, err := mockStoreRepository.GetStoreNames(ctx)
+	if err != nil {
+		t.Errorf("GetStoreNames failed, got err = %v, want nil.", err)
+	}
+	if !slices.Contains(names, "storeNamesFoo") {
+		t.Errorf("GetStoreNames failed, got = %v, want to contain 'storeNamesFoo'.", names)
+	}
+	if !slices.IsSorted(names) {
+		t.Errorf("GetStoreNames failed, got = %v, want sorted by name.", names)
+	}
+}
+
+func Test_GetStoreInfoAfterOpen(t *testing.T) {
+	t1, _ := newMockTransaction(t, ForWriting, -1)
+	t1.Begin()
+	NewBtree[int, string](ctx, sop.StoreOptions{
+		Name:                     "storeInfoFoo",
+		SlotLength:               16,
+		IsUnique:                 true,
+		IsValueDataInNodeSegment: false,
+		LeafLoadBalancing:        true,
+		Description:              "",
+	}, t1)
+	if err := t1.Commit(ctx); err != nil {
+		t.Errorf("Commit returned error, details: %v.", err)
+	}
+
+	t2, _ := newMockTransaction(t, ForReading, -1)
+	t2.Begin()
+	b3, err := OpenBtree[int, string](ctx, "storeInfoFoo", t2)
+	if err != nil {
+		t.Errorf("OpenBtree('storeInfoFoo') failed, got err = %v, want nil.", err)
+		return
+	}
+	si := b3.GetStoreInfo()
+	if si.Name != "storeInfoFoo" || si.SlotLength != 16 || !si.IsUnique || si.IsValueDataInNodeSegment || !si.LeafLoadBalancing {
+		t.Errorf("GetStoreInfo() failed, got = %v, want settings used in NewBtree.", si)
+	}
+	t2.Commit(ctx)
+}
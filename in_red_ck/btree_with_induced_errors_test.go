@@ -81,6 +81,13 @@ func (b3 b3WithInducedErrors[TK, TV]) FindOneWithID(ctx context.Context, key TK,
 	}
 	return true, nil
 }
+func (b3 b3WithInducedErrors[TK, TV]) FindOneOrPrevious(ctx context.Context, key TK) (bool, error) {
+	b3.t.Helper()
+	if b3.induceErrorOnMethod == 15 {
+		return false, fmt.Errorf("foobar")
+	}
+	return true, nil
+}
 func (b3 b3WithInducedErrors[TK, TV]) GetCurrentKey() TK {
 	b3.t.Helper()
 	var zero TK
@@ -136,3 +143,4 @@ func (b3 b3WithInducedErrors[TK, TV]) Previous(ctx context.Context) (bool, error
 
 func (b3 b3WithInducedErrors[TK, TV]) IsValueDataInNodeSegment() bool { return true }
 func (b3 b3WithInducedErrors[TK, TV]) IsUnique() bool                 { return true }
+func (b3 b3WithInducedErrors[TK, TV]) GetStoreInfo() btree.StoreInfo  { return btree.StoreInfo{} }
@@ -155,6 +155,20 @@ func (b3 *btreeWithTransaction[TK, TV]) FindOneWithID(ctx context.Context, key T
 	return r, err
 }
 
+// FindOneOrPrevious will search Btree for an item with a given key or, if not found, the item with the
+// greatest key less than the given key. Return true if such item is found, otherwise false.
+func (b3 *btreeWithTransaction[TK, TV]) FindOneOrPrevious(ctx context.Context, key TK) (bool, error) {
+	if !b3.transaction.HasBegun() {
+		b3.transaction.Rollback(ctx)
+		return false, fmt.Errorf(transHasNotBegunErrorMsg)
+	}
+	r, err := b3.btree.FindOneOrPrevious(ctx, key)
+	if err != nil {
+		b3.transaction.Rollback(ctx)
+	}
+	return r, err
+}
+
 // GetCurrentKey returns the current item's key.
 func (b3 *btreeWithTransaction[TK, TV]) GetCurrentKey() TK {
 	var zero TK
@@ -260,3 +274,8 @@ func (b3 *btreeWithTransaction[TK, TV]) IsValueDataInNodeSegment() bool {
 func (b3 *btreeWithTransaction[TK, TV]) IsUnique() bool {
 	return b3.btree.IsUnique()
 }
+
+// GetStoreInfo returns the B-Tree's store info, e.g. to check the settings it was created with.
+func (b3 *btreeWithTransaction[TK, TV]) GetStoreInfo() btree.StoreInfo {
+	return b3.btree.GetStoreInfo()
+}
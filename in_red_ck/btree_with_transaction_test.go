@@ -272,3 +272,19 @@ func Test_TransactionWithInducedErrorOnPrevious(t *testing.T) {
 		t.Error("Transaction is not rolled back.")
 	}
 }
+
+func Test_TransactionWithInducedErrorOnFindOneOrPrevious(t *testing.T) {
+	t2, _ := newMockTransaction(t, ForWriting, -1)
+	t2.Begin()
+
+	var t3 interface{} = t2.GetPhasedTransaction()
+	trans := t3.(*transaction)
+
+	b3 := newBTreeWithInducedErrors[int, string](t)
+	b3t := newBtreeWithTransaction(trans, b3)
+	b3.induceErrorOnMethod = 15
+	b3t.FindOneOrPrevious(ctx, 1)
+	if trans.HasBegun() {
+		t.Error("Transaction is not rolled back.")
+	}
+}
@@ -1,6 +1,7 @@
 package cassandra
 
 import (
+	"context"
 	"fmt"
 	"sync"
 	"time"
@@ -20,6 +21,9 @@ type Config struct {
 	ConnectionTimeout time.Duration
 	// Authenticator.
 	Authenticator gocql.Authenticator
+	// SslOptions (optional) enables TLS/SSL, e.g. specify the CaPath, CertPath & KeyPath of the PEM files
+	// if cluster requires client certificates & EnableHostVerification to verify the hosts' certificates.
+	SslOptions *gocql.SslOptions
 	// Defaults to "simple strategy & replication factor of 1".
 	ReplicationClause string
 
@@ -95,6 +99,9 @@ func OpenConnection(config Config) (*Connection, error) {
 		// Clear the authenticator just to be safer, we don't need to keep it hanging around.
 		config.Authenticator = nil
 	}
+	if config.SslOptions != nil {
+		cluster.SslOpts = config.SslOptions
+	}
 	var c = Connection{
 		Config: config,
 	}
@@ -131,3 +138,16 @@ func CloseConnection() {
 		connection = nil
 	}
 }
+
+// Ping issues a lightweight query to the Cassandra cluster using the singleton connection and returns the round trip duration.
+// Useful as a health check, it returns an error if connection is closed or cluster is unreachable.
+func Ping(ctx context.Context) (time.Duration, error) {
+	if connection == nil {
+		return 0, fmt.Errorf("Cassandra connection is closed, 'call OpenConnection(config) to open it")
+	}
+	startTime := time.Now()
+	if err := connection.Session.Query("SELECT now() FROM system.local;").WithContext(ctx).Exec(); err != nil {
+		return 0, err
+	}
+	return time.Since(startTime), nil
+}
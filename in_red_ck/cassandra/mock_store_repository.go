@@ -2,6 +2,7 @@ package cassandra
 
 import (
 	"context"
+	"sort"
 
 	"github.com/SharedCode/sop/btree"
 )
@@ -46,6 +47,15 @@ func (sr *mockStoreRepository) Get(ctx context.Context, names ...string) ([]btre
 	return stores, nil
 }
 
+func (sr *mockStoreRepository) GetStoreNames(ctx context.Context) ([]string, error) {
+	storeNames := make([]string, 0, len(sr.lookup))
+	for name := range sr.lookup {
+		storeNames = append(storeNames, name)
+	}
+	sort.Strings(storeNames)
+	return storeNames, nil
+}
+
 func (sr *mockStoreRepository) Remove(ctx context.Context, names ...string) error {
 	for _, name := range names {
 		delete(sr.lookup, name)
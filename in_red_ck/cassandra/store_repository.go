@@ -4,6 +4,7 @@ import (
 	"context"
 	"fmt"
 	log "log/slog"
+	"sort"
 	"strings"
 	"time"
 
@@ -22,6 +23,8 @@ import (
 type StoreRepository interface {
 	// Fetch store info with name.
 	Get(context.Context, ...string) ([]btree.StoreInfo, error)
+	// Returns the names of all the stores, sorted by name.
+	GetStoreNames(context.Context) ([]string, error)
 	// Add store info & create related tables like for registry & for node blob.
 	Add(context.Context, ...btree.StoreInfo) error
 	// Update store info. Update should also merge the Count of items between the incoming store info
@@ -245,6 +248,30 @@ func (sr *storeRepository) Get(ctx context.Context, names ...string) ([]btree.St
 	return stores, nil
 }
 
+// GetStoreNames returns the names of all the stores, sorted by name.
+func (sr *storeRepository) GetStoreNames(ctx context.Context) ([]string, error) {
+	if connection == nil {
+		return nil, fmt.Errorf("Cassandra connection is closed, 'call OpenConnection(config) to open it")
+	}
+	selectStatement := fmt.Sprintf("SELECT name FROM %s.store;", connection.Config.Keyspace)
+	qry := connection.Session.Query(selectStatement).WithContext(ctx)
+	if connection.Config.ConsistencyBook.StoreGet > gocql.Any {
+		qry.Consistency(connection.Config.ConsistencyBook.StoreGet)
+	}
+
+	iter := qry.Iter()
+	storeNames := make([]string, 0)
+	var name string
+	for iter.Scan(&name) {
+		storeNames = append(storeNames, name)
+	}
+	if err := iter.Close(); err != nil {
+		return nil, err
+	}
+	sort.Strings(storeNames)
+	return storeNames, nil
+}
+
 func (sr *storeRepository) Remove(ctx context.Context, names ...string) error {
 	if connection == nil {
 		return fmt.Errorf("Cassandra connection is closed, 'call OpenConnection(config) to open it")
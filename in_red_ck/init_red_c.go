@@ -1,6 +1,9 @@
 package in_red_ck
 
 import (
+	"context"
+	"time"
+
 	cas "github.com/SharedCode/sop/in_red_ck/cassandra"
 	"github.com/SharedCode/sop/in_red_ck/redis"
 )
@@ -27,3 +30,30 @@ func Shutdown() {
 	cas.CloseConnection()
 	redis.CloseConnection()
 }
+
+// HealthReport contains the health status of each of the sub-systems used by this package.
+// Both are required, Cassandra is the primary store & Redis is used for caching and for locking during commit.
+type HealthReport struct {
+	// CassandraError is nil if Cassandra is reachable.
+	CassandraError error
+	// CassandraLatency is the round trip duration of the Cassandra health check query.
+	CassandraLatency time.Duration
+	// RedisError is nil if Redis is reachable.
+	RedisError error
+	// RedisLatency is the round trip duration of the Redis PING.
+	RedisLatency time.Duration
+}
+
+// Returns true if all sub-systems are reachable, false otherwise.
+func (hr HealthReport) IsHealthy() bool {
+	return hr.CassandraError == nil && hr.RedisError == nil
+}
+
+// HealthCheck pings each of the sub-systems used by this package & reports their status, e.g. useful
+// in your app's readiness probe so it can stop accepting traffic if Cassandra or Redis is down.
+func HealthCheck(ctx context.Context) HealthReport {
+	var hr HealthReport
+	hr.CassandraLatency, hr.CassandraError = cas.Ping(ctx)
+	hr.RedisLatency, hr.RedisError = redis.Ping(ctx)
+	return hr
+}
@@ -2,6 +2,7 @@ package integration_tests
 
 import (
 	"context"
+	"slices"
 	"testing"
 
 	"github.com/SharedCode/sop"
@@ -101,3 +102,87 @@ func Test_TransactionStory_SingleBTree(t *testing.T) {
 		t.Errorf("Commit returned error, details: %v.", err)
 	}
 }
+
+func Test_BtreeExists(t *testing.T) {
+	if ok, err := in_red_ck.BtreeExists(ctx, "noSuchStore"); ok || err != nil {
+		t.Errorf("BtreeExists('noSuchStore') failed, got(ok, err) = %v, %v, want = false, nil.", ok, err)
+	}
+	trans, _ := in_red_ck.NewTransaction(in_red_ck.ForWriting, -1, false)
+	trans.Begin()
+	in_red_ck.NewBtree[int, string](ctx, sop.StoreOptions{
+		Name:                     "existStore",
+		SlotLength:               8,
+		IsUnique:                 false,
+		IsValueDataInNodeSegment: true,
+		LeafLoadBalancing:        false,
+		Description:              "",
+	}, trans)
+	if err := trans.Commit(ctx); err != nil {
+		t.Errorf("Commit returned error, details: %v.", err)
+	}
+	if ok, err := in_red_ck.BtreeExists(ctx, "existStore"); !ok || err != nil {
+		t.Errorf("BtreeExists('existStore') failed, got(ok, err) = %v, %v, want = true, nil.", ok, err)
+	}
+}
+
+func Test_Ping(t *testing.T) {
+	if _, err := redis.Ping(ctx); err != nil {
+		t.Errorf("redis.Ping failed, got err = %v, want nil.", err)
+	}
+	if err := redis.NewClient().Ping(ctx); err != nil {
+		t.Errorf("redis Cache.Ping failed, got err = %v, want nil.", err)
+	}
+	if _, err := cas.Ping(ctx); err != nil {
+		t.Errorf("cassandra.Ping failed, got err = %v, want nil.", err)
+	}
+}
+
+func Test_PoolStats(t *testing.T) {
+	if _, err := redis.Ping(ctx); err != nil {
+		t.Errorf("redis.Ping failed, got err = %v, want nil.", err)
+	}
+	stats, err := redis.PoolStats()
+	if stats == nil || err != nil {
+		t.Errorf("redis.PoolStats failed, got(stats, err) = %v, %v, want = stats, nil.", stats, err)
+		return
+	}
+	if stats.TotalConns < 1 {
+		t.Errorf("redis.PoolStats failed, got TotalConns = %v, want >= 1 after Ping.", stats.TotalConns)
+	}
+}
+
+func Test_HealthCheck(t *testing.T) {
+	hr := in_red_ck.HealthCheck(ctx)
+	if !hr.IsHealthy() {
+		t.Errorf("HealthCheck failed, got CassandraError, RedisError = %v, %v, want both nil.", hr.CassandraError, hr.RedisError)
+	}
+	if hr.CassandraLatency <= 0 || hr.RedisLatency <= 0 {
+		t.Errorf("HealthCheck failed, got CassandraLatency, RedisLatency = %v, %v, want both > 0.", hr.CassandraLatency, hr.RedisLatency)
+	}
+}
+
+func Test_GetBtreeNames(t *testing.T) {
+	trans, _ := in_red_ck.NewTransaction(in_red_ck.ForWriting, -1, false)
+	trans.Begin()
+	in_red_ck.NewBtree[int, string](ctx, sop.StoreOptions{
+		Name:                     "storeNamesBar",
+		SlotLength:               8,
+		IsUnique:                 false,
+		IsValueDataInNodeSegment: true,
+		LeafLoadBalancing:        false,
+		Description:              "",
+	}, trans)
+	if err := trans.Commit(ctx); err != nil {
+		t.Errorf("Commit returned error, details: %v.", err)
+	}
+	names, err := in_red_ck.GetBtreeNames(ctx)
+	if err != nil {
+		t.Errorf("GetBtreeNames failed, got err = %v, want nil.", err)
+	}
+	if !slices.Contains(names, "storeNamesBar") {
+		t.Errorf("GetBtreeNames failed, got = %v, want to contain 'storeNamesBar'.", names)
+	}
+	if !slices.IsSorted(names) {
+		t.Errorf("GetBtreeNames failed, got = %v, want sorted by name.", names)
+	}
+}
@@ -18,6 +18,23 @@ func RemoveBtree(ctx context.Context, name string) error {
 	return storeRepository.Remove(ctx, name)
 }
 
+// BtreeExists returns true if B-Tree with a given name exists in the backend storage, otherwise false.
+// Useful to check before calling NewBtree, which creates the B-Tree if it does not exist yet.
+func BtreeExists(ctx context.Context, name string) (bool, error) {
+	storeRepository := cas.NewStoreRepository()
+	stores, err := storeRepository.Get(ctx, name)
+	if err != nil {
+		return false, err
+	}
+	return len(stores) > 0 && !stores[0].IsEmpty(), nil
+}
+
+// GetBtreeNames returns the names of all the B-Trees in the backend storage, sorted by name.
+func GetBtreeNames(ctx context.Context) ([]string, error) {
+	storeRepository := cas.NewStoreRepository()
+	return storeRepository.GetStoreNames(ctx)
+}
+
 // OpenBtree will open an existing B-Tree instance & prepare it for use in a transaction.
 func OpenBtree[TK btree.Comparable, TV any](ctx context.Context, name string, t Transaction) (btree.BtreeInterface[TK, TV], error) {
 	if t == nil {
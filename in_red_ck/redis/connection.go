@@ -1,7 +1,11 @@
 package redis
 
 import (
+	"context"
 	"crypto/tls"
+	"crypto/x509"
+	"fmt"
+	"os"
 	"sync"
 
 	"time"
@@ -17,10 +21,36 @@ type Options struct {
 	Password string
 	// DB to connect to.
 	DB int
+	// URL (optional) of the Redis server, e.g. "redis://<user>:<password>@localhost:6379/<db>" or "rediss://..." for TLS.
+	// If set, it takes precedence over Address, Password & DB. An explicitly set TLSConfig takes precedence over
+	// the TLS config implied by the "rediss" scheme. Not used in Sentinel or Cluster mode.
+	URL string
 	// DefaultDurationInSeconds specifies the default caching time to use if not specified on call.
 	DefaultDurationInSeconds int
-	// TLS config.
+	// TLS config. Set it to connect to a Redis server that requires TLS, e.g. use NewTLSConfig
+	// to load the CA & client certificates from PEM files.
 	TLSConfig *tls.Config
+	// PoolSize is the maximum number of socket connections. Defaults to 10 connections per CPU if not set.
+	PoolSize int
+	// MaxIdleConns is the maximum number of idle connections kept in the pool. No limit if not set.
+	MaxIdleConns int
+	// ConnectionTimeout is the dial timeout when establishing new connections. Defaults to 5 seconds if not set.
+	ConnectionTimeout time.Duration
+	// ReadTimeout is the timeout for socket reads. Defaults to 3 seconds if not set, -1 means no timeout.
+	ReadTimeout time.Duration
+	// WriteTimeout is the timeout for socket writes. Defaults to ReadTimeout if not set.
+	WriteTimeout time.Duration
+
+	// SentinelMasterName, if set, connects via Redis Sentinel for automatic failover. The client will
+	// ask the Sentinel servers listed in SentinelAddresses for the address of this master.
+	// Takes precedence over ClusterAddresses if both are set.
+	SentinelMasterName string
+	// SentinelAddresses lists the Sentinel servers' addresses. Used only if SentinelMasterName is set.
+	SentinelAddresses []string
+	// ClusterAddresses, if set, connects to a Redis Cluster using these as seed nodes' addresses.
+	// Address & DB are ignored in cluster mode, as Redis Cluster only supports DB 0.
+	// Ignored if SentinelMasterName is set.
+	ClusterAddresses []string
 }
 
 // Returns the default duration.
@@ -30,7 +60,7 @@ func (opt *Options) GetDefaultDuration() time.Duration {
 
 // Connection contains Redis client connection object and the Options used to connect.
 type Connection struct {
-	Client  *redis.Client
+	Client  redis.UniversalClient
 	Options Options
 }
 
@@ -44,6 +74,35 @@ func DefaultOptions() Options {
 	}
 }
 
+// NewTLSConfig creates a TLS config from PEM encoded certificate files, suitable for Options.TLSConfig.
+// caCertPath (optional) is the CA certificate used to verify the server, otherwise the host's root CAs are used.
+// clientCertPath & clientKeyPath (optional) are the client certificate & key pair, needed if server requires client authentication.
+// insecureSkipVerify true will not verify the server certificate, use only for testing.
+func NewTLSConfig(caCertPath string, clientCertPath string, clientKeyPath string, insecureSkipVerify bool) (*tls.Config, error) {
+	tlsConfig := &tls.Config{
+		InsecureSkipVerify: insecureSkipVerify,
+	}
+	if caCertPath != "" {
+		caCert, err := os.ReadFile(caCertPath)
+		if err != nil {
+			return nil, err
+		}
+		caCertPool := x509.NewCertPool()
+		if !caCertPool.AppendCertsFromPEM(caCert) {
+			return nil, fmt.Errorf("can't parse CA certificate from '%s'", caCertPath)
+		}
+		tlsConfig.RootCAs = caCertPool
+	}
+	if clientCertPath != "" || clientKeyPath != "" {
+		cert, err := tls.LoadX509KeyPair(clientCertPath, clientKeyPath)
+		if err != nil {
+			return nil, err
+		}
+		tlsConfig.Certificates = []tls.Certificate{cert}
+	}
+	return tlsConfig, nil
+}
+
 var connection *Connection
 var mux sync.Mutex
 
@@ -64,12 +123,10 @@ func OpenConnection(options Options) (*Connection, error) {
 		return connection, nil
 	}
 
-	client := redis.NewClient(&redis.Options{
-		TLSConfig: options.TLSConfig,
-		Addr:      options.Address,
-		Password:  options.Password,
-		DB:        options.DB})
-
+	client, err := newClient(options)
+	if err != nil {
+		return nil, err
+	}
 	c := Connection{
 		Client:  client,
 		Options: options,
@@ -78,6 +135,69 @@ func OpenConnection(options Options) (*Connection, error) {
 	return connection, nil
 }
 
+// Creates the Redis client matching the topology specified in the options, i.e. - Sentinel, Cluster or standalone.
+func newClient(options Options) (redis.UniversalClient, error) {
+	if options.SentinelMasterName != "" {
+		return redis.NewFailoverClient(&redis.FailoverOptions{
+			MasterName:    options.SentinelMasterName,
+			SentinelAddrs: options.SentinelAddresses,
+			TLSConfig:     options.TLSConfig,
+			Password:      options.Password,
+			DB:            options.DB,
+			PoolSize:      options.PoolSize,
+			MaxIdleConns:  options.MaxIdleConns,
+			DialTimeout:   options.ConnectionTimeout,
+			ReadTimeout:   options.ReadTimeout,
+			WriteTimeout:  options.WriteTimeout}), nil
+	}
+	if len(options.ClusterAddresses) > 0 {
+		return redis.NewClusterClient(&redis.ClusterOptions{
+			Addrs:        options.ClusterAddresses,
+			TLSConfig:    options.TLSConfig,
+			Password:     options.Password,
+			PoolSize:     options.PoolSize,
+			MaxIdleConns: options.MaxIdleConns,
+			DialTimeout:  options.ConnectionTimeout,
+			ReadTimeout:  options.ReadTimeout,
+			WriteTimeout: options.WriteTimeout}), nil
+	}
+	if options.URL != "" {
+		ro, err := redis.ParseURL(options.URL)
+		if err != nil {
+			return nil, err
+		}
+		if options.TLSConfig != nil {
+			ro.TLSConfig = options.TLSConfig
+		}
+		if options.PoolSize > 0 {
+			ro.PoolSize = options.PoolSize
+		}
+		if options.MaxIdleConns > 0 {
+			ro.MaxIdleConns = options.MaxIdleConns
+		}
+		if options.ConnectionTimeout != 0 {
+			ro.DialTimeout = options.ConnectionTimeout
+		}
+		if options.ReadTimeout != 0 {
+			ro.ReadTimeout = options.ReadTimeout
+		}
+		if options.WriteTimeout != 0 {
+			ro.WriteTimeout = options.WriteTimeout
+		}
+		return redis.NewClient(ro), nil
+	}
+	return redis.NewClient(&redis.Options{
+		TLSConfig:    options.TLSConfig,
+		Addr:         options.Address,
+		Password:     options.Password,
+		DB:           options.DB,
+		PoolSize:     options.PoolSize,
+		MaxIdleConns: options.MaxIdleConns,
+		DialTimeout:  options.ConnectionTimeout,
+		ReadTimeout:  options.ReadTimeout,
+		WriteTimeout: options.WriteTimeout}), nil
+}
+
 // Close the singleton connection if open.
 func CloseConnection() {
 	if connection != nil {
@@ -90,3 +210,26 @@ func CloseConnection() {
 		connection = nil
 	}
 }
+
+// Ping sends a PING to the Redis server using the singleton connection and returns the round trip duration.
+// Useful as a health check, it returns an error if connection is not open or server is unreachable.
+func Ping(ctx context.Context) (time.Duration, error) {
+	if connection == nil {
+		return 0, fmt.Errorf("Redis connection is not open, 'call OpenConnection(options) to open it")
+	}
+	startTime := time.Now()
+	if err := connection.Client.Ping(ctx).Err(); err != nil {
+		return 0, err
+	}
+	return time.Since(startTime), nil
+}
+
+// PoolStats returns the connection pool statistics of the singleton connection, i.e. - hits, misses & timeouts
+// when getting a connection from the pool and the total, idle & stale connection counts.
+// Useful for monitoring, e.g. rising misses & timeouts signal the pool is undersized for the load.
+func PoolStats() (*redis.PoolStats, error) {
+	if connection == nil {
+		return nil, fmt.Errorf("Redis connection is not open, 'call OpenConnection(options) to open it")
+	}
+	return connection.Client.PoolStats(), nil
+}
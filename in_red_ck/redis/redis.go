@@ -41,19 +41,10 @@ func KeyNotFound(err error) bool {
 	return err == redis.Nil
 }
 
-// Ping tests connectivity for redis (PONG should be returned)
+// Ping tests connectivity for redis, see the package level Ping function.
 func (c client) Ping(ctx context.Context) error {
-	if connection == nil {
-		return fmt.Errorf("Redis connection is not open, 'can't create new client")
-	}
-	pong, err := connection.Client.Ping(ctx).Result()
-	if err != nil {
-		return err
-	}
-	fmt.Println(pong, err)
-	// Output: PONG <nil>
-
-	return nil
+	_, err := Ping(ctx)
+	return err
 }
 
 // Set executes the redis Set command
@@ -115,6 +106,17 @@ func (c client) Delete(ctx context.Context, keys ...string) error {
 	if connection == nil {
 		return fmt.Errorf("Redis connection is not open, 'can't create new client")
 	}
+	// Redis Cluster rejects a multi-key DEL whose keys map to different hash slots (CROSSSLOT),
+	// thus, in cluster mode, issue a DEL per key in a pipeline which the client routes to the owning nodes.
+	if cc, ok := connection.Client.(*redis.ClusterClient); ok && len(keys) > 1 {
+		_, err := cc.Pipelined(ctx, func(pipe redis.Pipeliner) error {
+			for _, key := range keys {
+				pipe.Del(ctx, key)
+			}
+			return nil
+		})
+		return err
+	}
 	var r = connection.Client.Del(ctx, keys...)
 	return r.Err()
 }
@@ -4,6 +4,9 @@ import (
 	"context"
 	"fmt"
 	"testing"
+	"time"
+
+	"github.com/redis/go-redis/v9"
 	// "golang.org/x/sync/errgroup"
 )
 
@@ -54,3 +57,152 @@ func TestBasicUse(t *testing.T) {
 		t.Error("Struct foo still exists after delete.")
 	}
 }
+
+func TestNewTLSConfig(t *testing.T) {
+	tlsConfig, err := NewTLSConfig("", "", "", true)
+	if err != nil {
+		t.Errorf("NewTLSConfig failed, got err = %v, want nil.", err)
+	}
+	if !tlsConfig.InsecureSkipVerify {
+		t.Errorf("NewTLSConfig failed, got InsecureSkipVerify = false, want true.")
+	}
+	if _, err := NewTLSConfig("/not/existing/ca.pem", "", "", false); err == nil {
+		t.Errorf("NewTLSConfig failed, got nil, want error on missing CA file.")
+	}
+}
+
+func TestNewClientWithURL(t *testing.T) {
+	if _, err := newClient(Options{URL: "foo://localhost:6379"}); err == nil {
+		t.Errorf("newClient failed, got nil, want error on invalid URL scheme.")
+	}
+	c, err := newClient(Options{URL: "rediss://localhost:6379/2"})
+	if err != nil {
+		t.Errorf("newClient failed, got err = %v, want nil.", err)
+		return
+	}
+	defer c.Close()
+	if c.(*redis.Client).Options().TLSConfig == nil {
+		t.Errorf("newClient failed, got nil TLSConfig, want TLSConfig set by 'rediss' scheme.")
+	}
+}
+
+func TestNewClientPoolAndTimeoutOptions(t *testing.T) {
+	c, err := newClient(Options{
+		Address:           "localhost:6380",
+		DB:                3,
+		PoolSize:          7,
+		MaxIdleConns:      3,
+		ConnectionTimeout: 2 * time.Second,
+		ReadTimeout:       4 * time.Second,
+		WriteTimeout:      6 * time.Second,
+	})
+	if err != nil {
+		t.Errorf("newClient failed, got err = %v, want nil.", err)
+		return
+	}
+	defer c.Close()
+	o := c.(*redis.Client).Options()
+	if o.Addr != "localhost:6380" || o.DB != 3 {
+		t.Errorf("newClient failed, got Addr, DB = %v, %v, want = localhost:6380, 3.", o.Addr, o.DB)
+	}
+	if o.PoolSize != 7 || o.MaxIdleConns != 3 {
+		t.Errorf("newClient failed, got PoolSize, MaxIdleConns = %v, %v, want = 7, 3.", o.PoolSize, o.MaxIdleConns)
+	}
+	if o.DialTimeout != 2*time.Second || o.ReadTimeout != 4*time.Second || o.WriteTimeout != 6*time.Second {
+		t.Errorf("newClient failed, got DialTimeout, ReadTimeout, WriteTimeout = %v, %v, %v, want = 2s, 4s, 6s.",
+			o.DialTimeout, o.ReadTimeout, o.WriteTimeout)
+	}
+}
+
+func TestNewClientTopologies(t *testing.T) {
+	poolAndTimeouts := Options{
+		PoolSize:          7,
+		MaxIdleConns:      3,
+		ConnectionTimeout: 2 * time.Second,
+		ReadTimeout:       4 * time.Second,
+		WriteTimeout:      6 * time.Second,
+	}
+	checkClientOptions := func(name string, o *redis.Options) {
+		t.Helper()
+		if o.PoolSize != 7 || o.MaxIdleConns != 3 ||
+			o.DialTimeout != 2*time.Second || o.ReadTimeout != 4*time.Second || o.WriteTimeout != 6*time.Second {
+			t.Errorf("%s: newClient failed, got pool & timeout options = %v, %v, %v, %v, %v, want = 7, 3, 2s, 4s, 6s.",
+				name, o.PoolSize, o.MaxIdleConns, o.DialTimeout, o.ReadTimeout, o.WriteTimeout)
+		}
+	}
+
+	// Sentinel gives a failover client, which is a *redis.Client with a fake address.
+	so := poolAndTimeouts
+	so.SentinelMasterName = "mymaster"
+	so.SentinelAddresses = []string{"localhost:26379"}
+	// Sentinel takes precedence over Cluster.
+	so.ClusterAddresses = []string{"localhost:7000"}
+	c, err := newClient(so)
+	if err != nil {
+		t.Errorf("Sentinel: newClient failed, got err = %v, want nil.", err)
+		return
+	}
+	if fc, ok := c.(*redis.Client); !ok || fc.Options().Addr != "FailoverClient" {
+		t.Errorf("Sentinel: newClient failed, got %T, want failover *redis.Client.", c)
+	} else {
+		checkClientOptions("Sentinel", fc.Options())
+	}
+	c.Close()
+
+	// Cluster gives a *redis.ClusterClient.
+	co := poolAndTimeouts
+	co.ClusterAddresses = []string{"localhost:7000", "localhost:7001"}
+	c, err = newClient(co)
+	if err != nil {
+		t.Errorf("Cluster: newClient failed, got err = %v, want nil.", err)
+		return
+	}
+	if cc, ok := c.(*redis.ClusterClient); !ok {
+		t.Errorf("Cluster: newClient failed, got %T, want *redis.ClusterClient.", c)
+	} else {
+		o := cc.Options()
+		if len(o.Addrs) != 2 || o.PoolSize != 7 || o.MaxIdleConns != 3 ||
+			o.DialTimeout != 2*time.Second || o.ReadTimeout != 4*time.Second || o.WriteTimeout != 6*time.Second {
+			t.Errorf("Cluster: newClient failed, got options = %v, %v, %v, %v, %v, %v, want = 2 addresses, 7, 3, 2s, 4s, 6s.",
+				o.Addrs, o.PoolSize, o.MaxIdleConns, o.DialTimeout, o.ReadTimeout, o.WriteTimeout)
+		}
+	}
+	c.Close()
+
+	// URL gives a standalone *redis.Client, with the options overriding the URL's defaults.
+	uo := poolAndTimeouts
+	uo.URL = "redis://localhost:6379/2"
+	c, err = newClient(uo)
+	if err != nil {
+		t.Errorf("URL: newClient failed, got err = %v, want nil.", err)
+		return
+	}
+	if uc, ok := c.(*redis.Client); !ok || uc.Options().Addr != "localhost:6379" || uc.Options().DB != 2 {
+		t.Errorf("URL: newClient failed, got %T, want standalone *redis.Client to localhost:6379 DB 2.", c)
+	} else {
+		checkClientOptions("URL", uc.Options())
+	}
+	c.Close()
+
+	// Default gives a standalone *redis.Client.
+	do := poolAndTimeouts
+	do.Address = "localhost:6380"
+	c, err = newClient(do)
+	if err != nil {
+		t.Errorf("Default: newClient failed, got err = %v, want nil.", err)
+		return
+	}
+	if sc, ok := c.(*redis.Client); !ok || sc.Options().Addr != "localhost:6380" {
+		t.Errorf("Default: newClient failed, got %T, want standalone *redis.Client to localhost:6380.", c)
+	} else {
+		checkClientOptions("Default", sc.Options())
+	}
+	c.Close()
+}
+
+func TestPoolStatsWithoutConnection(t *testing.T) {
+	CloseConnection()
+	if stats, err := PoolStats(); err == nil || stats != nil {
+		t.Errorf("PoolStats failed, got(stats, err) = %v, %v, want = nil, error as connection is not open.", stats, err)
+	}
+}
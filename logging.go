@@ -0,0 +1,76 @@
+package sop
+
+import (
+	"io"
+	log "log/slog"
+	"os"
+	"sync"
+)
+
+// LogFormat enumeration.
+type LogFormat int
+
+const (
+	// TextFormat outputs each log entry as a line of key=value pairs.
+	TextFormat = iota
+	// JSONFormat outputs each log entry as a JSON object, e.g. for log pipelines that ingest JSON.
+	JSONFormat
+)
+
+// LoggingOptions contains the settings of the logger used by SOP.
+type LoggingOptions struct {
+	// Level is the minimum level of the log entries that get written.
+	Level log.Level
+	// Format of the log entries, TextFormat or JSONFormat.
+	Format LogFormat
+	// Path of the file where logs get appended to. Logs are written to stderr if both Path & Writer are not set.
+	Path string
+	// Writer (optional) where logs get written to, e.g. os.Stdout for containerized deployments. Takes precedence over Path.
+	Writer io.Writer
+}
+
+var logFile *os.File
+var logMux sync.Mutex
+var logLevel = new(log.LevelVar)
+
+// ConfigureLogging sets the default (slog) logger, which SOP packages log to, as specified in the options.
+// Log file opened by a previous call, if there is, gets closed.
+func ConfigureLogging(options LoggingOptions) error {
+	logMux.Lock()
+	defer logMux.Unlock()
+
+	var w io.Writer = os.Stderr
+	var f *os.File
+	if options.Writer != nil {
+		w = options.Writer
+	} else if options.Path != "" {
+		var err error
+		if f, err = os.OpenFile(options.Path, os.O_CREATE|os.O_WRONLY|os.O_APPEND, 0644); err != nil {
+			return err
+		}
+		w = f
+	}
+	logLevel.Set(options.Level)
+	ho := &log.HandlerOptions{
+		Level: logLevel,
+	}
+	var h log.Handler
+	if options.Format == JSONFormat {
+		h = log.NewJSONHandler(w, ho)
+	} else {
+		h = log.NewTextHandler(w, ho)
+	}
+	log.SetDefault(log.New(h))
+
+	if logFile != nil {
+		logFile.Close()
+	}
+	logFile = f
+	return nil
+}
+
+// SetLogLevel changes the minimum level of the log entries that get written by the logger set up in ConfigureLogging.
+// Takes effect immediately on succeeding log calls, e.g. useful to turn on Debug level while investigating a live issue.
+func SetLogLevel(level log.Level) {
+	logLevel.Set(level)
+}
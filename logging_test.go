@@ -0,0 +1,117 @@
+package sop
+
+import (
+	"bytes"
+	"encoding/json"
+	"errors"
+	log "log/slog"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+// Restores the default logger & log level after a test reconfigured them.
+func restoreLogging(t *testing.T) {
+	t.Helper()
+	defaultLogger := log.Default()
+	level := logLevel.Level()
+	t.Cleanup(func() {
+		log.SetDefault(defaultLogger)
+		logLevel.Set(level)
+	})
+}
+
+func TestConfigureLoggingJSONFormat(t *testing.T) {
+	restoreLogging(t)
+	var buf bytes.Buffer
+	if err := ConfigureLogging(LoggingOptions{Format: JSONFormat, Writer: &buf}); err != nil {
+		t.Fatalf("ConfigureLogging failed, got err = %v, want nil.", err)
+	}
+	log.Info("hello")
+
+	var entry map[string]any
+	if err := json.Unmarshal(buf.Bytes(), &entry); err != nil {
+		t.Fatalf("JSON log entry failed to parse, got err = %v, entry = %s.", err, buf.String())
+	}
+	for _, k := range []string{"time", "level", "msg"} {
+		if _, ok := entry[k]; !ok {
+			t.Errorf("JSON log entry failed, got = %v, want to contain '%s'.", entry, k)
+		}
+	}
+	if entry["level"] != "INFO" || entry["msg"] != "hello" {
+		t.Errorf("JSON log entry failed, got level, msg = %v, %v, want = INFO, hello.", entry["level"], entry["msg"])
+	}
+}
+
+func TestConfigureLoggingTextFormatIsDefault(t *testing.T) {
+	restoreLogging(t)
+	var buf bytes.Buffer
+	if err := ConfigureLogging(LoggingOptions{Writer: &buf}); err != nil {
+		t.Fatalf("ConfigureLogging failed, got err = %v, want nil.", err)
+	}
+	log.Info("hello")
+
+	if !strings.Contains(buf.String(), "level=INFO msg=hello") {
+		t.Errorf("Text log entry failed, got = %s, want to contain 'level=INFO msg=hello'.", buf.String())
+	}
+}
+
+func TestConfigureLoggingClosesPreviousFile(t *testing.T) {
+	restoreLogging(t)
+	dir := t.TempDir()
+	if err := ConfigureLogging(LoggingOptions{Path: filepath.Join(dir, "sop1.log")}); err != nil {
+		t.Fatalf("ConfigureLogging failed, got err = %v, want nil.", err)
+	}
+	log.Info("hello")
+	f1 := logFile
+	if err := ConfigureLogging(LoggingOptions{Path: filepath.Join(dir, "sop2.log")}); err != nil {
+		t.Fatalf("ConfigureLogging failed, got err = %v, want nil.", err)
+	}
+	t.Cleanup(func() {
+		ConfigureLogging(LoggingOptions{Writer: os.Stderr})
+	})
+
+	if _, err := f1.WriteString("foo"); !errors.Is(err, os.ErrClosed) {
+		t.Errorf("Previous log file failed to close, got err = %v, want os.ErrClosed.", err)
+	}
+	ba, err := os.ReadFile(filepath.Join(dir, "sop1.log"))
+	if err != nil || !strings.Contains(string(ba), "msg=hello") {
+		t.Errorf("Log file failed, got = %s, %v, want to contain 'msg=hello'.", string(ba), err)
+	}
+}
+
+func TestSetLogLevel(t *testing.T) {
+	restoreLogging(t)
+	var buf bytes.Buffer
+	if err := ConfigureLogging(LoggingOptions{Level: log.LevelInfo, Writer: &buf}); err != nil {
+		t.Fatalf("ConfigureLogging failed, got err = %v, want nil.", err)
+	}
+	log.Debug("foo")
+	if buf.Len() > 0 {
+		t.Errorf("Debug log entry failed, got = %s, want nothing written at Info level.", buf.String())
+	}
+
+	SetLogLevel(log.LevelDebug)
+	log.Debug("bar")
+	if !strings.Contains(buf.String(), "level=DEBUG msg=bar") {
+		t.Errorf("Debug log entry failed, got = %s, want to contain 'level=DEBUG msg=bar'.", buf.String())
+	}
+}
+
+func TestConfigureLoggingWriterTakesPrecedenceOverPath(t *testing.T) {
+	restoreLogging(t)
+	var buf bytes.Buffer
+	path := filepath.Join(t.TempDir(), "sop.log")
+	if err := ConfigureLogging(LoggingOptions{Path: path, Writer: &buf}); err != nil {
+		t.Fatalf("ConfigureLogging failed, got err = %v, want nil.", err)
+	}
+	log.Info("hello")
+
+	if !strings.Contains(buf.String(), "msg=hello") {
+		t.Errorf("Writer log entry failed, got = %s, want to contain 'msg=hello'.", buf.String())
+	}
+	if _, err := os.Stat(path); !errors.Is(err, os.ErrNotExist) {
+		t.Errorf("Log file failed, got err = %v, want file not created when Writer is set.", err)
+	}
+}